@@ -6,6 +6,34 @@ use anyhow::{bail, ensure, Error, Result};
 use std::ops::Deref;
 use cv::DataType;
 
+// `image`'s Rgb/Rgba pixels are `R, G, B, (A)`; OpenCv's Mat is conventionally
+// `B, G, R, (A)` (what `imshow`/`imwrite`/`imread` expect). Plain `TryToCv` copies
+// bytes as-is (`Rgb`/`Rgba` order); use `TryToCvWithColorOrder` for the BGR(A) Mat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorOrder {
+    /// `R, G, B, (A)`, i.e. the `image` crate's native order.
+    Rgb,
+    /// `B, G, R, (A)`, i.e. OpenCv's native order for `imshow`/`imwrite`/`imread`.
+    Bgr,
+}
+
+// Like `TryToCv`, but lets the caller pick the channel order for 3/4-channel
+// colour conversions instead of always producing/consuming `image`'s native RGB(A).
+pub trait TryToCvWithColorOrder<T> {
+    type Error;
+
+    fn try_to_cv_with(&self, order: ColorOrder) -> Result<T, Self::Error>;
+}
+
+// Swaps channel 0 and channel 2 (red and blue) of every pixel in `data` in place,
+// directly on the contiguous sample slice.
+fn swap_rb_channels<T: Copy>(data: &mut [T], n_channels: usize) {
+    debug_assert!(n_channels == 3 || n_channels == 4);
+    for pixel in data.chunks_exact_mut(n_channels) {
+        pixel.swap(0, 2);
+    }
+}
+
 // &ImageBuffer -> Mat
 impl<P, Container> TryToCv<cv::Mat> for image::ImageBuffer<P, Container>
 where
@@ -34,6 +62,25 @@ where
     }
 }
 
+impl<P, Container> TryToCvWithColorOrder<cv::Mat> for image::ImageBuffer<P, Container>
+where
+    P: image::Pixel,
+    P::Subpixel: OpenCvElement + DataType,
+    Container: Deref<Target = [P::Subpixel]> + Clone,
+{
+    type Error = Error;
+
+    fn try_to_cv_with(&self, order: ColorOrder) -> Result<cv::Mat, Self::Error> {
+        let mut mat = self.try_to_cv()?;
+        let n_channels = P::CHANNEL_COUNT as usize;
+        if order == ColorOrder::Bgr && n_channels >= 3 {
+            let slice = mat.as_mut_slice::<P::Subpixel>()?;
+            swap_rb_channels(slice, n_channels);
+        }
+        Ok(mat)
+    }
+}
+
 // &DynamicImage -> Mat
 impl TryToCv<cv::Mat> for image::DynamicImage {
     type Error = Error;
@@ -78,9 +125,19 @@ impl TryToCv<image::DynamicImage> for cv::Mat {
         let image: image::DynamicImage = match (depth, n_channels) {
             (cv::CV_8U, 1) => mat_to_image_buffer_gray::<u8>(self, width, height).into(),
             (cv::CV_16U, 1) => mat_to_image_buffer_gray::<u16>(self, width, height).into(),
+            (cv::CV_8U, 2) => mat_to_image_buffer_luma_alpha::<u8>(self, width, height).into(),
+            (cv::CV_16U, 2) => mat_to_image_buffer_luma_alpha::<u16>(self, width, height).into(),
             (cv::CV_8U, 3) => mat_to_image_buffer_rgb::<u8>(self, width, height).into(),
             (cv::CV_16U, 3) => mat_to_image_buffer_rgb::<u16>(self, width, height).into(),
             (cv::CV_32F, 3) => mat_to_image_buffer_rgb::<f32>(self, width, height).into(),
+            (cv::CV_64F, 3) => mat_to_image_buffer_rgb_f64_as_f32(self, width, height).into(),
+            (cv::CV_8U, 4) => mat_to_image_buffer_rgba::<u8>(self, width, height).into(),
+            (cv::CV_16U, 4) => mat_to_image_buffer_rgba::<u16>(self, width, height).into(),
+            (cv::CV_32F, 4) => mat_to_image_buffer_rgba::<f32>(self, width, height).into(),
+            // `DynamicImage` has no signed-integer or 64-bit-float variants, so
+            // `CV_32S`/`CV_64F` single-channel Mats aren't reachable here. Convert
+            // them directly via `TryToCv<ImageBuffer<Luma<T>, _>> for Mat` instead,
+            // e.g. `let image: image::ImageBuffer<image::Luma<f64>, Vec<f64>> = mat.try_to_cv()?;`.
             _ => bail!("Mat of type {} is not supported", self.type_name()),
         };
 
@@ -89,6 +146,10 @@ impl TryToCv<image::DynamicImage> for cv::Mat {
 }
 
 // &Mat -> gray ImageBuffer
+//
+// Generic over `T`, so besides `u8`/`u16`/`f32` this also covers the `CV_32S`
+// (`i32`) and `CV_64F` (`f64`) depths that `TryToCv<DynamicImage>` can't represent,
+// as long as `T: OpenCvElement` is implemented for them.
 impl<T> TryToCv<image::ImageBuffer<image::Luma<T>, Vec<T>>> for cv::Mat
 where
     image::Luma<T>: image::Pixel,
@@ -152,8 +213,200 @@ where
     }
 }
 
+impl<T> TryToCvWithColorOrder<image::ImageBuffer<image::Rgb<T>, Vec<T>>> for cv::Mat
+where
+    image::Rgb<T>: image::Pixel<Subpixel = T>,
+    T: OpenCvElement + image::Primitive + DataType,
+{
+    type Error = Error;
+
+    fn try_to_cv_with(
+        &self,
+        order: ColorOrder,
+    ) -> Result<image::ImageBuffer<image::Rgb<T>, Vec<T>>, Self::Error> {
+        let mut image: image::ImageBuffer<image::Rgb<T>, Vec<T>> = self.try_to_cv()?;
+        if order == ColorOrder::Bgr {
+            swap_rb_channels(image.as_mut(), 3);
+        }
+        Ok(image)
+    }
+}
+
+// &Mat -> luma-alpha ImageBuffer
+impl<T> TryToCv<image::ImageBuffer<image::LumaA<T>, Vec<T>>> for cv::Mat
+where
+    image::LumaA<T>: image::Pixel<Subpixel = T>,
+    T: OpenCvElement + image::Primitive + DataType,
+{
+    type Error = Error;
+
+    fn try_to_cv(&self) -> Result<image::ImageBuffer<image::LumaA<T>, Vec<T>>, Self::Error> {
+        let rows = self.rows();
+        let cols = self.cols();
+        ensure!(
+            rows != -1 && cols != -1,
+            "Mat with more than 2 dimensions is not supported."
+        );
+
+        let depth = self.depth();
+        let n_channels = self.channels();
+        let width = cols as u32;
+        let height = rows as u32;
+
+        ensure!(
+            n_channels == 2,
+            "Expect 2 channels, but get {n_channels} channels"
+        );
+        ensure!(depth == T::DEPTH, "Subpixel type is not supported");
+
+        let image = mat_to_image_buffer_luma_alpha::<T>(self, width, height);
+        Ok(image)
+    }
+}
+
+// &Mat -> rgba ImageBuffer
+impl<T> TryToCv<image::ImageBuffer<image::Rgba<T>, Vec<T>>> for cv::Mat
+where
+    image::Rgba<T>: image::Pixel<Subpixel = T>,
+    T: OpenCvElement + image::Primitive + DataType,
+{
+    type Error = Error;
+
+    fn try_to_cv(&self) -> Result<image::ImageBuffer<image::Rgba<T>, Vec<T>>, Self::Error> {
+        let rows = self.rows();
+        let cols = self.cols();
+        ensure!(
+            rows != -1 && cols != -1,
+            "Mat with more than 2 dimensions is not supported."
+        );
+
+        let depth = self.depth();
+        let n_channels = self.channels();
+        let width = cols as u32;
+        let height = rows as u32;
+
+        ensure!(
+            n_channels == 4,
+            "Expect 4 channels, but get {n_channels} channels"
+        );
+        ensure!(depth == T::DEPTH, "Subpixel type is not supported");
+
+        let image = mat_to_image_buffer_rgba::<T>(self, width, height);
+        Ok(image)
+    }
+}
+
+impl<T> TryToCvWithColorOrder<image::ImageBuffer<image::Rgba<T>, Vec<T>>> for cv::Mat
+where
+    image::Rgba<T>: image::Pixel<Subpixel = T>,
+    T: OpenCvElement + image::Primitive + DataType,
+{
+    type Error = Error;
+
+    fn try_to_cv_with(
+        &self,
+        order: ColorOrder,
+    ) -> Result<image::ImageBuffer<image::Rgba<T>, Vec<T>>, Self::Error> {
+        let mut image: image::ImageBuffer<image::Rgba<T>, Vec<T>> = self.try_to_cv()?;
+        if order == ColorOrder::Bgr {
+            swap_rb_channels(image.as_mut(), 4);
+        }
+        Ok(image)
+    }
+}
+
+// Borrows `mat`'s existing buffer as an `image::FlatSamples` view instead of copying
+// it into a fresh `ImageBuffer`, for hot loops that would otherwise allocate per frame.
+// Requires `mat` to be continuous with a matching depth/channel count; use the owning
+// `TryToCv` impls above when a `'static` buffer is needed instead.
+pub trait TryToCvView<'a, P>
+where
+    P: image::Pixel,
+{
+    type Error;
+
+    fn try_to_cv_view(&'a self) -> Result<image::FlatSamples<&'a [P::Subpixel]>, Self::Error>;
+}
+
+impl<'a, P> TryToCvView<'a, P> for cv::Mat
+where
+    P: image::Pixel + 'a,
+    P::Subpixel: OpenCvElement + DataType,
+{
+    type Error = Error;
+
+    fn try_to_cv_view(&'a self) -> Result<image::FlatSamples<&'a [P::Subpixel]>, Self::Error> {
+        let rows = self.rows();
+        let cols = self.cols();
+        ensure!(
+            rows != -1 && cols != -1,
+            "Mat with more than 2 dimensions is not supported."
+        );
+        ensure!(
+            self.is_continuous(),
+            "cannot borrow a non-continuous Mat as a view; copy it into a continuous \
+             Mat first (e.g. via `Mat::roi(..)?.try_clone()?`)"
+        );
+
+        let depth = self.depth();
+        let n_channels = self.channels() as usize;
+        ensure!(
+            depth == P::Subpixel::DEPTH,
+            "Subpixel type is not supported"
+        );
+        ensure!(
+            n_channels == P::CHANNEL_COUNT as usize,
+            "Expect {} channels, but get {n_channels} channels",
+            P::CHANNEL_COUNT
+        );
+
+        let width = cols as usize;
+        let height = rows as usize;
+        let samples = self.as_slice::<P::Subpixel>()?;
+
+        Ok(image::FlatSamples {
+            samples,
+            layout: image::flat::SampleLayout {
+                channels: n_channels as u8,
+                channel_stride: 1,
+                width: width as u32,
+                width_stride: n_channels,
+                height: height as u32,
+                height_stride: width * n_channels,
+            },
+            color_hint: None,
+        })
+    }
+}
+
 // Utility functions
 
+// Copies `mat`'s samples into a packed, row-major `Vec<T>`. Falls back to a row-by-row
+// copy using `mat_step` when `mat` (e.g. an ROI) isn't tightly packed and `as_slice`
+// fails, instead of the slow `at_2d`-per-pixel path.
+fn mat_to_packed_vec<T>(mat: &cv::Mat, width: u32, height: u32, n_channels: usize) -> Vec<T>
+where
+    T: OpenCvElement + DataType,
+{
+    if let Ok(slice) = mat.as_slice::<T>() {
+        return slice.to_vec();
+    }
+
+    // Walk rows by byte offset rather than dividing `mat_step()` by `size_of::<T>()`:
+    // the row step is only guaranteed to be a multiple of the element size when OpenCv
+    // allocated the Mat itself, not for Mats wrapping externally-owned buffers.
+    let row_len = width as usize * n_channels;
+    let step_bytes = mat.mat_step()[0];
+    let base = mat.data();
+
+    let mut packed = Vec::with_capacity(row_len * height as usize);
+    for row in 0..height as usize {
+        let row_ptr = unsafe { base.add(row * step_bytes) } as *const T;
+        packed.extend((0..row_len).map(|col| unsafe { row_ptr.add(col).read_unaligned() }));
+    }
+    packed
+}
+
 fn mat_to_image_buffer_gray<T>(
     mat: &cv::Mat,
     width: u32,
@@ -163,16 +416,12 @@ where
     T: image::Primitive + OpenCvElement + DataType,
 {
     type Image<T> = image::ImageBuffer<image::Luma<T>, Vec<T>>;
-
-    match mat.as_slice::<T>() {
-        Ok(slice) => Image::<T>::from_vec(width, height, slice.to_vec()).unwrap(),
-        Err(_) => Image::<T>::from_fn(width, height, |col, row| {
-            let pixel: T = *mat.at_2d(row as i32, col as i32).unwrap();
-            image::Luma([pixel])
-        }),
-    }
+    Image::<T>::from_vec(width, height, mat_to_packed_vec::<T>(mat, width, height, 1)).unwrap()
 }
 
+/// Copies `mat` into an `ImageBuffer<Rgb<T>, _>` assuming `mat`'s 3 channels are
+/// already in `R, G, B` order. Use [`TryToCvWithColorOrder::try_to_cv_with`] with
+/// [`ColorOrder::Bgr`] if `mat` instead holds OpenCv's native `B, G, R` order.
 fn mat_to_image_buffer_rgb<T>(
     mat: &cv::Mat,
     width: u32,
@@ -183,14 +432,46 @@ where
     image::Rgb<T>: image::Pixel<Subpixel = T>,
 {
     type Image<T> = image::ImageBuffer<image::Rgb<T>, Vec<T>>;
+    Image::<T>::from_vec(width, height, mat_to_packed_vec::<T>(mat, width, height, 3)).unwrap()
+}
 
-    match mat.as_slice::<T>() {
-        Ok(slice) => Image::<T>::from_vec(width, height, slice.to_vec()).unwrap(),
-        Err(_) => Image::<T>::from_fn(width, height, |col, row| {
-            let cv::Point3_::<T> { x, y, z } = *mat.at_2d(row as i32, col as i32).unwrap();
-            image::Rgb([x, y, z])
-        }),
-    }
+/// Copies a `CV_64F`, 3-channel `mat` into an `Rgb32F` buffer, narrowing each `f64`
+/// sample to `f32`. `DynamicImage` has no 64-bit-float variant, so this is the
+/// widest float precision it can represent.
+fn mat_to_image_buffer_rgb_f64_as_f32(
+    mat: &cv::Mat,
+    width: u32,
+    height: u32,
+) -> image::ImageBuffer<image::Rgb<f32>, Vec<f32>> {
+    let samples = mat_to_packed_vec::<f64>(mat, width, height, 3);
+    let samples = samples.into_iter().map(|v| v as f32).collect();
+    image::ImageBuffer::from_vec(width, height, samples).unwrap()
+}
+
+fn mat_to_image_buffer_luma_alpha<T>(
+    mat: &cv::Mat,
+    width: u32,
+    height: u32,
+) -> image::ImageBuffer<image::LumaA<T>, Vec<T>>
+where
+    T: image::Primitive + OpenCvElement + DataType,
+    image::LumaA<T>: image::Pixel<Subpixel = T>,
+{
+    type Image<T> = image::ImageBuffer<image::LumaA<T>, Vec<T>>;
+    Image::<T>::from_vec(width, height, mat_to_packed_vec::<T>(mat, width, height, 2)).unwrap()
+}
+
+fn mat_to_image_buffer_rgba<T>(
+    mat: &cv::Mat,
+    width: u32,
+    height: u32,
+) -> image::ImageBuffer<image::Rgba<T>, Vec<T>>
+where
+    T: image::Primitive + OpenCvElement + DataType,
+    image::Rgba<T>: image::Pixel<Subpixel = T>,
+{
+    type Image<T> = image::ImageBuffer<image::Rgba<T>, Vec<T>>;
+    Image::<T>::from_vec(width, height, mat_to_packed_vec::<T>(mat, width, height, 4)).unwrap()
 }
 
 #[cfg(test)]
@@ -199,7 +480,8 @@ mod tests {
     use crate::opencv::{core as cv, prelude::*};
     use crate::with_opencv::MatExt;
     use crate::TryToCv;
-    use anyhow::{ensure, Result};
+    use crate::with_opencv_image::{ColorOrder, TryToCvView, TryToCvWithColorOrder};
+    use anyhow::{bail, ensure, Result};
     use itertools::iproduct;
 
     #[test]
@@ -247,4 +529,220 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn convert_opencv_image_color_order() -> Result<()> {
+        const WIDTH: u32 = 16;
+        const HEIGHT: u32 = 12;
+
+        // ImageBuffer -> Mat
+        {
+            let image = image::RgbImage::from_fn(WIDTH, HEIGHT, |x, y| {
+                image::Rgb([x as u8, y as u8, (x + y) as u8])
+            });
+
+            let mat_default: Mat = image.try_to_cv()?;
+            let mat_rgb: Mat = image.try_to_cv_with(ColorOrder::Rgb)?;
+            let mat_bgr: Mat = image.try_to_cv_with(ColorOrder::Bgr)?;
+
+            iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+                let image::Rgb([r, g, b]) = image[(col, row)];
+                let p1: cv::Point3_<u8> = *mat_default.at_2d(row as i32, col as i32)?;
+                let p2: cv::Point3_<u8> = *mat_rgb.at_2d(row as i32, col as i32)?;
+                let p3: cv::Point3_<u8> = *mat_bgr.at_2d(row as i32, col as i32)?;
+                ensure!(p1 == cv::Point3_ { x: r, y: g, z: b });
+                ensure!(p2 == p1, "ColorOrder::Rgb should leave channels untouched");
+                ensure!(
+                    p3 == (cv::Point3_ { x: b, y: g, z: r }),
+                    "ColorOrder::Bgr should swap R and B"
+                );
+                anyhow::Ok(())
+            })?;
+        }
+
+        // Mat -> ImageBuffer, the reverse direction
+        {
+            let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_8UC3)?;
+            let rgb: image::ImageBuffer<image::Rgb<u8>, Vec<u8>> = mat.try_to_cv()?;
+            let rgb_same: image::ImageBuffer<image::Rgb<u8>, Vec<u8>> =
+                TryToCvWithColorOrder::try_to_cv_with(&mat, ColorOrder::Rgb)?;
+            let bgr_swapped: image::ImageBuffer<image::Rgb<u8>, Vec<u8>> =
+                TryToCvWithColorOrder::try_to_cv_with(&mat, ColorOrder::Bgr)?;
+
+            iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+                let image::Rgb([r, g, b]) = rgb[(col, row)];
+                let same = rgb_same[(col, row)];
+                let swapped = bgr_swapped[(col, row)];
+                ensure!(same.0 == [r, g, b], "ColorOrder::Rgb should leave channels untouched");
+                ensure!(swapped.0 == [b, g, r], "ColorOrder::Bgr should swap R and B");
+                anyhow::Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_opencv_image_signed_and_double() -> Result<()> {
+        const WIDTH: usize = 250;
+        const HEIGHT: usize = 100;
+
+        // CV_32S (signed int) gray
+        {
+            let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_32SC1)?;
+            let image: image::ImageBuffer<image::Luma<i32>, Vec<i32>> = mat.try_to_cv()?;
+
+            iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+                let p1: i32 = *mat.at_2d(row as i32, col as i32)?;
+                let p2 = image[(col as u32, row as u32)].0[0];
+                ensure!(p1 == p2);
+                anyhow::Ok(())
+            })?;
+        }
+
+        // CV_64F (double) gray
+        {
+            let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_64FC1)?;
+            let image: image::ImageBuffer<image::Luma<f64>, Vec<f64>> = mat.try_to_cv()?;
+
+            iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+                let p1: f64 = *mat.at_2d(row as i32, col as i32)?;
+                let p2 = image[(col as u32, row as u32)].0[0];
+                ensure!(p1 == p2);
+                anyhow::Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_opencv_image_rgba_and_luma_alpha() -> Result<()> {
+        const WIDTH: usize = 37;
+        const HEIGHT: usize = 21;
+
+        // rgba
+        {
+            let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_8UC4)?;
+            let image: image::RgbaImage = mat.try_to_cv()?;
+            let mat2: Mat = image.try_to_cv()?;
+
+            iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+                let p1: cv::Vec4b = *mat.at_2d(row as i32, col as i32)?;
+                let p2: image::Rgba<u8> = image[(col as u32, row as u32)];
+                let p3: cv::Vec4b = *mat2.at_2d(row as i32, col as i32)?;
+                ensure!(p1.0 == p2.0 && p1.0 == p3.0);
+                anyhow::Ok(())
+            })?;
+
+            let dynamic: image::DynamicImage = mat.try_to_cv()?;
+            ensure!(
+                matches!(dynamic, image::DynamicImage::ImageRgba8(_)),
+                "expected ImageRgba8 for a CV_8UC4 Mat"
+            );
+        }
+
+        // luma-alpha
+        {
+            let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_8UC2)?;
+            let image: image::ImageBuffer<image::LumaA<u8>, Vec<u8>> = mat.try_to_cv()?;
+            let mat2: Mat = image.try_to_cv()?;
+
+            iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+                let p1: cv::Vec2b = *mat.at_2d(row as i32, col as i32)?;
+                let p2: image::LumaA<u8> = image[(col as u32, row as u32)];
+                let p3: cv::Vec2b = *mat2.at_2d(row as i32, col as i32)?;
+                ensure!(p1.0 == p2.0 && p1.0 == p3.0);
+                anyhow::Ok(())
+            })?;
+
+            let dynamic: image::DynamicImage = mat.try_to_cv()?;
+            ensure!(
+                matches!(dynamic, image::DynamicImage::ImageLumaA8(_)),
+                "expected ImageLumaA8 for a CV_8UC2 Mat"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_opencv_image_double_rgb() -> Result<()> {
+        const WIDTH: usize = 37;
+        const HEIGHT: usize = 21;
+
+        let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_64FC3)?;
+
+        // direct typed path: Mat -> ImageBuffer<Rgb<f64>, _>
+        let image: image::ImageBuffer<image::Rgb<f64>, Vec<f64>> = mat.try_to_cv()?;
+        iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+            let cv::Point3_::<f64> { x, y, z } = *mat.at_2d(row as i32, col as i32)?;
+            let p2 = image[(col as u32, row as u32)].0;
+            ensure!([x, y, z] == p2);
+            anyhow::Ok(())
+        })?;
+
+        // `TryToCv<DynamicImage>` narrows CV_64F to `Rgb32F`, since `DynamicImage` has
+        // no 64-bit-float variant.
+        let dynamic: image::DynamicImage = mat.try_to_cv()?;
+        let image::DynamicImage::ImageRgb32F(image32) = dynamic else {
+            bail!("expected ImageRgb32F for a CV_64FC3 Mat");
+        };
+        iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+            let cv::Point3_::<f64> { x, y, z } = *mat.at_2d(row as i32, col as i32)?;
+            let p2 = image32[(col as u32, row as u32)].0;
+            ensure!([x as f32, y as f32, z as f32] == p2);
+            anyhow::Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_opencv_image_view() -> Result<()> {
+        const WIDTH: usize = 250;
+        const HEIGHT: usize = 100;
+
+        let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_8UC3)?;
+        let view = TryToCvView::<image::Rgb<u8>>::try_to_cv_view(&mat)?;
+
+        iproduct!(0..HEIGHT, 0..WIDTH).try_for_each(|(row, col)| {
+            let p1: cv::Point3_<u8> = *mat.at_2d(row as i32, col as i32)?;
+            let offset = (row * WIDTH + col) * 3;
+            let p2 = &view.samples[offset..offset + 3];
+            ensure!({
+                let cv::Point3_ { x, y, z } = p1;
+                p2 == [x, y, z]
+            });
+            anyhow::Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_opencv_image_roi() -> Result<()> {
+        const WIDTH: usize = 250;
+        const HEIGHT: usize = 100;
+        const ROI_WIDTH: usize = 60;
+        const ROI_HEIGHT: usize = 40;
+
+        let mat = Mat::new_randn_2d(HEIGHT as i32, WIDTH as i32, cv::CV_8UC3)?;
+        let roi = mat.roi(cv::Rect::new(10, 5, ROI_WIDTH as i32, ROI_HEIGHT as i32))?;
+        ensure!(!roi.is_continuous(), "expected a non-continuous ROI Mat");
+
+        let image: image::RgbImage = roi.try_to_cv()?;
+
+        iproduct!(0..ROI_HEIGHT, 0..ROI_WIDTH).try_for_each(|(row, col)| {
+            let p1: cv::Point3_<u8> = *roi.at_2d(row as i32, col as i32)?;
+            let p2: image::Rgb<u8> = image[(col as u32, row as u32)];
+            ensure!({
+                let cv::Point3_ { x, y, z } = p1;
+                [x, y, z] == p2.0
+            });
+            anyhow::Ok(())
+        })?;
+
+        Ok(())
+    }
 }